@@ -140,6 +140,30 @@ impl<T> List<T> {
     pub fn into_iter(self) -> IntoIter<T> {
         IntoIter(self)
     }
+
+    pub fn cursor_mut(&mut self) -> CursorMut<T> {
+        CursorMut {
+            list: self,
+            cur: None,
+            peek: None,
+        }
+    }
+
+    pub fn iter(&self) -> Iter<T> {
+        Iter {
+            front: self.head.clone(),
+            back: self.tail.clone(),
+            cur: None,
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<T> {
+        IterMut {
+            front: self.head.clone(),
+            back: self.tail.clone(),
+            cur: None,
+        }
+    }
 }
 
 impl<T> Drop for List<T> {
@@ -169,6 +193,248 @@ impl<T> DoubleEndedIterator for IntoIter<T> {
     }
 }
 
+// `front`/`back` each hold a *cloned* Rc, independent of the list itself, so
+// walking the iterator never borrows the list it came from. That's also why
+// these don't implement `Iterator`/`DoubleEndedIterator`: the `Ref`/`RefMut`
+// they hand back borrow from the iterator's own `cur` field for the
+// duration of the call, and the standard traits' fixed `Item` type has no
+// way to express an item whose lifetime is tied to each call to `next`.
+//
+// KNOWN LIMITATION: because of that, `Iter`/`IterMut` are NOT drop-in
+// `Iterator`s - `for x in list.iter()` does not work, and none of the
+// `Iterator` adapter methods (`map`, `filter`, `collect`, ...) are
+// available. Callers have to drive `next`/`next_back` by hand (e.g. via
+// `while let Some(x) = iter.next() { ... }`), same as `CursorMut`.
+pub struct Iter<T> {
+    front: Link<T>,
+    back: Link<T>,
+    // Holds the node the last `next`/`next_back` call is still lending out,
+    // so the returned `Ref` borrows from a field of `self` rather than a
+    // local that would be dropped before the caller is done with it.
+    cur: Link<T>,
+}
+
+impl<T> Iter<T> {
+    pub fn next(&mut self) -> Option<Ref<'_, T>> {
+        let node = self.front.take()?;
+
+        if self.back.as_ref().is_some_and(|back| Rc::ptr_eq(&node, back)) {
+            // front and back just met: that was the last element
+            self.back = None;
+        } else {
+            self.front = node.borrow().next.clone();
+        }
+
+        self.cur = Some(node);
+        self.cur
+            .as_ref()
+            .map(|node| Ref::map(node.borrow(), |node| &node.elem))
+    }
+
+    pub fn next_back(&mut self) -> Option<Ref<'_, T>> {
+        let node = self.back.take()?;
+
+        if self
+            .front
+            .as_ref()
+            .is_some_and(|front| Rc::ptr_eq(&node, front))
+        {
+            self.front = None;
+        } else {
+            self.back = node.borrow().prev.clone();
+        }
+
+        self.cur = Some(node);
+        self.cur
+            .as_ref()
+            .map(|node| Ref::map(node.borrow(), |node| &node.elem))
+    }
+}
+
+pub struct IterMut<T> {
+    front: Link<T>,
+    back: Link<T>,
+    cur: Link<T>,
+}
+
+impl<T> IterMut<T> {
+    pub fn next(&mut self) -> Option<RefMut<'_, T>> {
+        let node = self.front.take()?;
+
+        if self.back.as_ref().is_some_and(|back| Rc::ptr_eq(&node, back)) {
+            self.back = None;
+        } else {
+            self.front = node.borrow().next.clone();
+        }
+
+        self.cur = Some(node);
+        self.cur
+            .as_ref()
+            .map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.elem))
+    }
+
+    pub fn next_back(&mut self) -> Option<RefMut<'_, T>> {
+        let node = self.back.take()?;
+
+        if self
+            .front
+            .as_ref()
+            .is_some_and(|front| Rc::ptr_eq(&node, front))
+        {
+            self.front = None;
+        } else {
+            self.back = node.borrow().prev.clone();
+        }
+
+        self.cur = Some(node);
+        self.cur
+            .as_ref()
+            .map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.elem))
+    }
+}
+
+// A cursor lets us walk into the middle of the list and splice nodes in or
+// out without shifting anything else around. `cur: None` is the "ghost"
+// position, sitting between the tail and the head.
+pub struct CursorMut<'a, T> {
+    list: &'a mut List<T>,
+    cur: Link<T>,
+    // Scratch space for peek_next/peek_prev: the RefMut we hand back has to
+    // borrow from a field that lives as long as `self`, so we stash the
+    // peeked link here rather than in a local that would be dropped too soon.
+    peek: Link<T>,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    pub fn current(&mut self) -> Option<RefMut<T>> {
+        self.cur
+            .as_ref()
+            .map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.elem))
+    }
+
+    pub fn peek_next(&mut self) -> Option<RefMut<T>> {
+        self.peek = match &self.cur {
+            Some(cur) => cur.borrow().next.clone(),
+            None => self.list.head.clone(),
+        };
+        self.peek
+            .as_ref()
+            .map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.elem))
+    }
+
+    pub fn peek_prev(&mut self) -> Option<RefMut<T>> {
+        self.peek = match &self.cur {
+            Some(cur) => cur.borrow().prev.clone(),
+            None => self.list.tail.clone(),
+        };
+        self.peek
+            .as_ref()
+            .map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.elem))
+    }
+
+    pub fn move_next(&mut self) {
+        if let Some(cur) = self.cur.take() {
+            self.cur = cur.borrow().next.clone();
+        } else {
+            // we were at the ghost, wrap around to the head
+            self.cur = self.list.head.clone();
+        }
+        // drop any stale peek_next/peek_prev clone: otherwise it can keep
+        // the node we just moved onto refcounted above 1, and
+        // remove_current's try_unwrap would panic.
+        self.peek = None;
+    }
+
+    pub fn move_prev(&mut self) {
+        if let Some(cur) = self.cur.take() {
+            self.cur = cur.borrow().prev.clone();
+        } else {
+            // we were at the ghost, wrap around to the tail
+            self.cur = self.list.tail.clone();
+        }
+        self.peek = None;
+    }
+
+    pub fn insert_before(&mut self, elem: T) {
+        match self.cur.take() {
+            Some(cur) => {
+                let new = Node::new(elem);
+                match cur.borrow_mut().prev.take() {
+                    Some(old_prev) => {
+                        old_prev.borrow_mut().next = Some(Rc::clone(&new));
+                        new.borrow_mut().prev = Some(old_prev);
+                    }
+                    None => {
+                        // cur was the head, so new becomes the head
+                        self.list.head = Some(Rc::clone(&new));
+                    }
+                }
+                new.borrow_mut().next = Some(Rc::clone(&cur));
+                cur.borrow_mut().prev = Some(new);
+                self.cur = Some(cur);
+            }
+            // inserting before the ghost means inserting at the back
+            None => self.list.push_back(elem),
+        }
+    }
+
+    pub fn insert_after(&mut self, elem: T) {
+        match self.cur.take() {
+            Some(cur) => {
+                let new = Node::new(elem);
+                match cur.borrow_mut().next.take() {
+                    Some(old_next) => {
+                        old_next.borrow_mut().prev = Some(Rc::clone(&new));
+                        new.borrow_mut().next = Some(old_next);
+                    }
+                    None => {
+                        // cur was the tail, so new becomes the tail
+                        self.list.tail = Some(Rc::clone(&new));
+                    }
+                }
+                new.borrow_mut().prev = Some(Rc::clone(&cur));
+                cur.borrow_mut().next = Some(new);
+                self.cur = Some(cur);
+            }
+            // inserting after the ghost means inserting at the front
+            None => self.list.push_front(elem),
+        }
+    }
+
+    pub fn remove_current(&mut self) -> Option<T> {
+        let cur = self.cur.take()?;
+        let next = cur.borrow_mut().next.take();
+        let prev = cur.borrow_mut().prev.take();
+
+        match (&prev, &next) {
+            (Some(prev), Some(next)) => {
+                prev.borrow_mut().next = Some(Rc::clone(next));
+                next.borrow_mut().prev = Some(Rc::clone(prev));
+            }
+            (Some(prev), None) => {
+                prev.borrow_mut().next = None;
+                self.list.tail = Some(Rc::clone(prev));
+            }
+            (None, Some(next)) => {
+                next.borrow_mut().prev = None;
+                self.list.head = Some(Rc::clone(next));
+            }
+            (None, None) => {
+                self.list.head = None;
+                self.list.tail = None;
+            }
+        }
+
+        // cur's prev/next are already None and its Rc is no longer reachable
+        // from the list, so its refcount is 1 and the unwrap can't fail.
+        self.cur = next;
+        // same as move_next: a stale peek clone of the node we now sit on
+        // would make the next remove_current's try_unwrap panic.
+        self.peek = None;
+        Some(Rc::try_unwrap(cur).ok().unwrap().into_inner().elem)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::List;
@@ -250,4 +516,165 @@ mod test {
         assert_eq!(list.pop_back(), Some(1));
         assert_eq!(list.pop_back(), None);
     }
+
+    #[test]
+    fn cursor_move_peek() {
+        let mut list = List::new();
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+
+        let mut cursor = list.cursor_mut();
+        assert!(cursor.current().is_none());
+        assert_eq!(cursor.peek_next().as_deref(), Some(&3));
+        assert_eq!(cursor.peek_prev().as_deref(), Some(&1));
+
+        cursor.move_next();
+        assert_eq!(cursor.current().as_deref(), Some(&3));
+        cursor.move_next();
+        assert_eq!(cursor.current().as_deref(), Some(&2));
+        cursor.move_next();
+        assert_eq!(cursor.current().as_deref(), Some(&1));
+        cursor.move_next();
+        // wrapped back to the ghost
+        assert!(cursor.current().is_none());
+        cursor.move_prev();
+        assert_eq!(cursor.current().as_deref(), Some(&1));
+    }
+
+    #[test]
+    fn cursor_insert_remove() {
+        let mut list = List::new();
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+
+        let mut cursor = list.cursor_mut();
+        cursor.move_next(); // 3
+        cursor.move_next(); // 2
+        cursor.insert_before(10);
+        cursor.insert_after(20);
+        // list is now 3, 10, 2, 20, 1
+
+        assert_eq!(cursor.remove_current(), Some(2));
+        // cursor now sits on 20
+        drop(cursor);
+
+        let mut check = list.into_iter();
+        assert_eq!(check.next(), Some(3));
+        assert_eq!(check.next(), Some(10));
+        assert_eq!(check.next(), Some(20));
+        assert_eq!(check.next(), Some(1));
+        assert_eq!(check.next(), None);
+    }
+
+    #[test]
+    fn cursor_remove_after_stale_peek() {
+        let mut list = List::new();
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+        // list is 3, 2, 1
+
+        let mut cursor = list.cursor_mut();
+        cursor.move_next(); // cur = 3
+        cursor.peek_next(); // stash a clone of node 2 in `peek`
+        cursor.move_next(); // cur = 2, the same node `peek` still clones
+        assert_eq!(cursor.remove_current(), Some(2));
+
+        drop(cursor);
+        let mut check = list.into_iter();
+        assert_eq!(check.next(), Some(3));
+        assert_eq!(check.next(), Some(1));
+        assert_eq!(check.next(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let mut list = List::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next().as_deref(), Some(&1));
+        assert_eq!(iter.next_back().as_deref(), Some(&3));
+        assert_eq!(iter.next().as_deref(), Some(&2));
+        assert!(iter.next_back().is_none());
+        assert!(iter.next().is_none());
+        drop(iter);
+
+        // the list itself is untouched
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_front(), Some(3));
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut list = List::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        let mut iter = list.iter_mut();
+        while let Some(mut val) = iter.next() {
+            *val *= 10;
+        }
+        drop(iter);
+
+        assert_eq!(list.pop_front(), Some(10));
+        assert_eq!(list.pop_front(), Some(20));
+        assert_eq!(list.pop_front(), Some(30));
+    }
+
+    #[test]
+    fn iter_empty() {
+        let list: List<i32> = List::new();
+        let mut iter = list.iter();
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+    }
+
+    #[test]
+    fn iter_single_element() {
+        let mut list = List::new();
+        list.push_back(1);
+
+        // front and back meet on the very first call
+        let mut iter = list.iter();
+        assert_eq!(iter.next().as_deref(), Some(&1));
+        assert!(iter.next_back().is_none());
+        assert!(iter.next().is_none());
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next_back().as_deref(), Some(&1));
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+    }
+
+    #[test]
+    fn iter_mut_empty() {
+        let mut list: List<i32> = List::new();
+        let mut iter = list.iter_mut();
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+    }
+
+    #[test]
+    fn iter_mut_single_element() {
+        let mut list = List::new();
+        list.push_back(1);
+
+        {
+            let mut iter = list.iter_mut();
+            {
+                let mut val = iter.next().unwrap();
+                *val *= 10;
+            }
+            assert!(iter.next_back().is_none());
+        }
+
+        assert_eq!(list.pop_front(), Some(10));
+    }
 }
@@ -0,0 +1,336 @@
+use std::cell::UnsafeCell;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+// AN INTRUSIVE DOUBLY-LINKED LIST (NO ALLOCATION)
+
+// Every other list in this crate owns its nodes: pushing an element means
+// boxing it up (or Rc-wrapping it) and handing that allocation to the list.
+// Here the element embeds its own `Links<T>`, so the list never allocates
+// anything itself. The tradeoff is that the caller must prove, via unsafe
+// impls of `Linked`, that the links really do live inside `Self` and that a
+// given `T` is only ever linked into one list at a time.
+
+/// The next/prev pointers embedded inside a linked element.
+///
+/// Wrapped in an `UnsafeCell` because the list mutates a node's links through
+/// a shared `&T` reached via a raw pointer from a neighboring node - there's
+/// no way to get a `&mut` to it without violating aliasing, so we reach for
+/// interior mutability instead and rely on `Linked`'s safety invariants.
+pub struct Links<T> {
+    inner: UnsafeCell<LinksInner<T>>,
+}
+
+struct LinksInner<T> {
+    next: Option<NonNull<T>>,
+    prev: Option<NonNull<T>>,
+}
+
+impl<T> Links<T> {
+    pub fn new() -> Self {
+        Links {
+            inner: UnsafeCell::new(LinksInner {
+                next: None,
+                prev: None,
+            }),
+        }
+    }
+
+    unsafe fn next(&self) -> Option<NonNull<T>> {
+        (*self.inner.get()).next
+    }
+
+    unsafe fn set_next(&self, next: Option<NonNull<T>>) {
+        (*self.inner.get()).next = next;
+    }
+
+    unsafe fn prev(&self) -> Option<NonNull<T>> {
+        (*self.inner.get()).prev
+    }
+
+    unsafe fn set_prev(&self, prev: Option<NonNull<T>>) {
+        (*self.inner.get()).prev = prev;
+    }
+}
+
+impl<T> Default for Links<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Lets a type be stored in an [`IntrusiveList`] without the list allocating
+/// a separate node for it.
+///
+/// # Safety
+///
+/// Implementors must ensure that `links` returns a pointer to a `Links<Self>`
+/// that is actually embedded in `*ptr` and stays valid for as long as `ptr`
+/// is linked into a list, and that `into_ptr`/`from_ptr` round-trip the same
+/// allocation (no double-frees, no aliasing `Handle`s).
+pub unsafe trait Linked: Sized {
+    /// The owning smart pointer (e.g. `Box<Self>`) handed to and returned
+    /// from the list.
+    type Handle;
+
+    /// Consume a handle, giving up ownership of the pointee to the list.
+    fn into_ptr(handle: Self::Handle) -> NonNull<Self>;
+
+    /// Recreate the handle that owns `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have come from `into_ptr` and must no longer be linked
+    /// into any list.
+    unsafe fn from_ptr(ptr: NonNull<Self>) -> Self::Handle;
+
+    /// Get the embedded links for the node at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point at a live, properly initialized `Self`.
+    unsafe fn links(ptr: NonNull<Self>) -> NonNull<Links<Self>>;
+}
+
+/// An allocation-free, intrusive doubly-linked list.
+///
+/// `T` carries its own [`Links<T>`], so pushing and popping never touch the
+/// allocator - the same object can be unlinked from one list and relinked
+/// into another with nothing but pointer writes.
+pub struct IntrusiveList<T: Linked> {
+    head: Option<NonNull<T>>,
+    tail: Option<NonNull<T>>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Linked> IntrusiveList<T> {
+    pub fn new() -> Self {
+        IntrusiveList {
+            head: None,
+            tail: None,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    pub fn push_front(&mut self, handle: T::Handle) {
+        let ptr = T::into_ptr(handle);
+        unsafe {
+            let links = T::links(ptr).as_ref();
+            links.set_prev(None);
+            links.set_next(self.head);
+            match self.head {
+                Some(old_head) => T::links(old_head).as_ref().set_prev(Some(ptr)),
+                None => self.tail = Some(ptr),
+            }
+        }
+        self.head = Some(ptr);
+    }
+
+    pub fn push_back(&mut self, handle: T::Handle) {
+        let ptr = T::into_ptr(handle);
+        unsafe {
+            let links = T::links(ptr).as_ref();
+            links.set_next(None);
+            links.set_prev(self.tail);
+            match self.tail {
+                Some(old_tail) => T::links(old_tail).as_ref().set_next(Some(ptr)),
+                None => self.head = Some(ptr),
+            }
+        }
+        self.tail = Some(ptr);
+    }
+
+    pub fn pop_front(&mut self) -> Option<T::Handle> {
+        let head = self.head?;
+        unsafe {
+            let links = T::links(head).as_ref();
+            let next = links.next();
+            self.head = next;
+            match next {
+                Some(next) => T::links(next).as_ref().set_prev(None),
+                None => self.tail = None,
+            }
+            links.set_next(None);
+            links.set_prev(None);
+            Some(T::from_ptr(head))
+        }
+    }
+
+    pub fn pop_back(&mut self) -> Option<T::Handle> {
+        let tail = self.tail?;
+        unsafe {
+            let links = T::links(tail).as_ref();
+            let prev = links.prev();
+            self.tail = prev;
+            match prev {
+                Some(prev) => T::links(prev).as_ref().set_next(None),
+                None => self.head = None,
+            }
+            links.set_next(None);
+            links.set_prev(None);
+            Some(T::from_ptr(tail))
+        }
+    }
+
+    /// Unlink the node at `ptr` from the list in O(1) and hand ownership
+    /// back to the caller.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must currently be linked into *this* list. Passing a pointer
+    /// that is unlinked, or linked into a different list, is undefined
+    /// behavior.
+    pub unsafe fn remove(&mut self, ptr: NonNull<T>) -> T::Handle {
+        let links = T::links(ptr).as_ref();
+        let prev = links.prev();
+        let next = links.next();
+
+        match prev {
+            Some(prev) => T::links(prev).as_ref().set_next(next),
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => T::links(next).as_ref().set_prev(prev),
+            None => self.tail = prev,
+        }
+
+        links.set_next(None);
+        links.set_prev(None);
+        T::from_ptr(ptr)
+    }
+}
+
+impl<T: Linked> Drop for IntrusiveList<T> {
+    fn drop(&mut self) {
+        // Walk the list popping handles so each element's own destructor
+        // actually runs instead of the node just being forgotten.
+        while self.pop_front().is_some() {}
+    }
+}
+
+impl<T: Linked> Default for IntrusiveList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{IntrusiveList, Linked, Links};
+    use std::cell::RefCell;
+    use std::ptr;
+    use std::ptr::NonNull;
+    use std::rc::Rc;
+
+    struct Entry {
+        links: Links<Entry>,
+        val: i32,
+    }
+
+    impl Entry {
+        fn new(val: i32) -> Box<Self> {
+            Box::new(Entry {
+                links: Links::new(),
+                val,
+            })
+        }
+    }
+
+    unsafe impl Linked for Entry {
+        type Handle = Box<Entry>;
+
+        fn into_ptr(handle: Self::Handle) -> NonNull<Self> {
+            NonNull::from(Box::leak(handle))
+        }
+
+        unsafe fn from_ptr(ptr: NonNull<Self>) -> Self::Handle {
+            Box::from_raw(ptr.as_ptr())
+        }
+
+        unsafe fn links(ptr: NonNull<Self>) -> NonNull<Links<Self>> {
+            NonNull::new_unchecked(ptr::addr_of_mut!((*ptr.as_ptr()).links))
+        }
+    }
+
+    #[test]
+    fn push_and_pop_both_ends() {
+        let mut list: IntrusiveList<Entry> = IntrusiveList::new();
+        list.push_back(Entry::new(1));
+        list.push_back(Entry::new(2));
+        list.push_front(Entry::new(0));
+
+        assert_eq!(list.pop_front().unwrap().val, 0);
+        assert_eq!(list.pop_front().unwrap().val, 1);
+        assert_eq!(list.pop_back().unwrap().val, 2);
+        assert!(list.pop_front().is_none());
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn remove_from_middle() {
+        let mut list: IntrusiveList<Entry> = IntrusiveList::new();
+        list.push_back(Entry::new(1));
+        let middle = Entry::new(2);
+        let middle_ptr = NonNull::from(&*middle);
+        list.push_back(middle);
+        list.push_back(Entry::new(3));
+
+        let removed = unsafe { list.remove(middle_ptr) };
+        assert_eq!(removed.val, 2);
+
+        assert_eq!(list.pop_front().unwrap().val, 1);
+        assert_eq!(list.pop_front().unwrap().val, 3);
+        assert!(list.pop_front().is_none());
+    }
+
+    #[test]
+    fn drop_runs_element_destructors() {
+        struct Tracked {
+            links: Links<Tracked>,
+            dropped: Rc<RefCell<Vec<i32>>>,
+            id: i32,
+        }
+
+        unsafe impl Linked for Tracked {
+            type Handle = Box<Tracked>;
+
+            fn into_ptr(handle: Self::Handle) -> NonNull<Self> {
+                NonNull::from(Box::leak(handle))
+            }
+
+            unsafe fn from_ptr(ptr: NonNull<Self>) -> Self::Handle {
+                Box::from_raw(ptr.as_ptr())
+            }
+
+            unsafe fn links(ptr: NonNull<Self>) -> NonNull<Links<Self>> {
+                NonNull::new_unchecked(ptr::addr_of_mut!((*ptr.as_ptr()).links))
+            }
+        }
+
+        impl Drop for Tracked {
+            fn drop(&mut self) {
+                self.dropped.borrow_mut().push(self.id);
+            }
+        }
+
+        let dropped = Rc::new(RefCell::new(Vec::new()));
+        {
+            let mut list: IntrusiveList<Tracked> = IntrusiveList::new();
+            list.push_back(Box::new(Tracked {
+                links: Links::new(),
+                dropped: Rc::clone(&dropped),
+                id: 1,
+            }));
+            list.push_back(Box::new(Tracked {
+                links: Links::new(),
+                dropped: Rc::clone(&dropped),
+                id: 2,
+            }));
+        }
+        assert_eq!(*dropped.borrow(), vec![1, 2]);
+    }
+}
@@ -62,6 +62,57 @@ impl<T> List<T> {
             next: self.head.as_deref_mut(),
         }
     }
+
+    // walks `at` nodes in, then hands the remainder of the chain off as a
+    // new list by taking it out of the `at`-th node's `next`
+    pub fn split_off(&mut self, at: usize) -> List<T> {
+        let mut cur = &mut self.head;
+        for _ in 0..at {
+            cur = match cur.as_mut() {
+                Some(node) => &mut node.next,
+                None => return List { head: None },
+            };
+        }
+        List { head: cur.take() }
+    }
+
+    // O(n): walks to the last `None` link and moves `other`'s head into it
+    pub fn append(&mut self, other: &mut List<T>) {
+        let mut tail = &mut self.head;
+        while let Some(node) = tail {
+            tail = &mut node.next;
+        }
+        *tail = other.head.take();
+    }
+
+    pub fn insert(&mut self, index: usize, elem: T) {
+        let mut cur = &mut self.head;
+        for _ in 0..index {
+            cur = match cur.as_mut() {
+                Some(node) => &mut node.next,
+                None => return,
+            };
+        }
+        let new_node = Box::new(Node {
+            elem,
+            next: cur.take(),
+        });
+        *cur = Some(new_node);
+    }
+
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let mut cur = &mut self.head;
+        for _ in 0..index {
+            cur = match cur.as_mut() {
+                Some(node) => &mut node.next,
+                None => return None,
+            };
+        }
+        cur.take().map(|node| {
+            *cur = node.next;
+            node.elem
+        })
+    }
 }
 
 impl<T> Drop for List<T> {
@@ -131,9 +182,135 @@ impl<'a, T> Iterator for IterMut<'a, T> {
 }
 
 
+// A FIFO queue, unlike `List` above which is LIFO. `push`/`pop` on `List`
+// both hit the head, so dequeueing in insertion order costs O(n). Here we
+// keep a raw `tail` pointer so `push_back` can write straight through the
+// old tail's `next` field instead of walking the whole list.
+pub struct Queue<T> {
+    head: Link<T>,
+    tail: *mut Node<T>,
+}
+
+impl<T> Queue<T> {
+    pub fn new() -> Self {
+        Queue {
+            head: None,
+            tail: std::ptr::null_mut(),
+        }
+    }
+
+    pub fn push_back(&mut self, elem: T) {
+        let mut new_tail = Box::new(Node { elem, next: None });
+        let raw_tail: *mut _ = &mut *new_tail;
+
+        if self.tail.is_null() {
+            self.head = Some(new_tail);
+        } else {
+            // the old tail has to have its `next` pointer updated to point
+            // at the new tail, via the raw pointer
+            unsafe {
+                (*self.tail).next = Some(new_tail);
+            }
+        }
+
+        self.tail = raw_tail;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.take().map(|head| {
+            let head = *head;
+            self.head = head.next;
+
+            if self.head.is_none() {
+                self.tail = std::ptr::null_mut();
+            }
+
+            head.elem
+        })
+    }
+
+    pub fn peek_front(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.elem)
+    }
+
+    pub fn peek_front_mut(&mut self) -> Option<&mut T> {
+        self.head.as_mut().map(|node| &mut node.elem)
+    }
+
+    pub fn into_iter(self) -> QueueIntoIter<T> {
+        QueueIntoIter(self)
+    }
+
+    pub fn iter(&self) -> QueueIter<'_, T> {
+        QueueIter {
+            next: self.head.as_deref(),
+        }
+    }
+
+    pub fn iter_mut(&mut self) -> QueueIterMut<'_, T> {
+        QueueIterMut {
+            next: self.head.as_deref_mut(),
+        }
+    }
+}
+
+impl<T> Drop for Queue<T> {
+    fn drop(&mut self) {
+        let mut cur_link = self.head.take();
+        while let Some(mut boxed_node) = cur_link {
+            cur_link = boxed_node.next.take();
+            // boxed_node goes out of scope and gets dropped here, same
+            // non-recursive trick as `List`'s Drop.
+        }
+    }
+}
+
+impl<T> Default for Queue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct QueueIntoIter<T>(Queue<T>);
+
+impl<T> Iterator for QueueIntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+}
+
+pub struct QueueIter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for QueueIter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.elem
+        })
+    }
+}
+
+pub struct QueueIterMut<'a, T> {
+    next: Option<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for QueueIterMut<'a, T> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.take().map(|node| {
+            self.next = node.next.as_deref_mut();
+            &mut node.elem
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::List;
+    use super::{List, Queue};
 
     #[test]
     fn iter_mut() {
@@ -220,4 +397,151 @@ mod test {
         assert_eq!(list.pop(), Some(1));
         assert_eq!(list.pop(), None);
     }
+
+    #[test]
+    fn queue_basics() {
+        let mut queue = Queue::new();
+
+        // Check empty queue behaves right
+        assert_eq!(queue.pop_front(), None);
+
+        // Populate the queue
+        queue.push_back(1);
+        queue.push_back(2);
+        queue.push_back(3);
+
+        // Check pop in insertion order (FIFO)
+        assert_eq!(queue.pop_front(), Some(1));
+        assert_eq!(queue.pop_front(), Some(2));
+
+        // Push some more
+        queue.push_back(4);
+
+        // Check exhaustion
+        assert_eq!(queue.pop_front(), Some(3));
+        assert_eq!(queue.pop_front(), Some(4));
+        assert_eq!(queue.pop_front(), None);
+
+        // Check the queue still works after being drained
+        queue.push_back(5);
+        assert_eq!(queue.pop_front(), Some(5));
+    }
+
+    #[test]
+    fn queue_peek() {
+        let mut queue = Queue::new();
+
+        assert_eq!(queue.peek_front(), None);
+        assert_eq!(queue.peek_front_mut(), None);
+
+        queue.push_back(1);
+        queue.push_back(2);
+
+        assert_eq!(queue.peek_front(), Some(&1));
+        if let Some(value) = queue.peek_front_mut() {
+            *value = 42;
+        }
+        assert_eq!(queue.peek_front(), Some(&42));
+        assert_eq!(queue.pop_front(), Some(42));
+    }
+
+    #[test]
+    fn queue_iter() {
+        let mut queue = Queue::new();
+        queue.push_back(1);
+        queue.push_back(2);
+        queue.push_back(3);
+
+        let mut iter = queue.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+
+        for elem in queue.iter_mut() {
+            *elem *= 10;
+        }
+
+        let mut into_iter = queue.into_iter();
+        assert_eq!(into_iter.next(), Some(10));
+        assert_eq!(into_iter.next(), Some(20));
+        assert_eq!(into_iter.next(), Some(30));
+        assert_eq!(into_iter.next(), None);
+    }
+
+    #[test]
+    fn split_off() {
+        let mut list = List::new();
+        list.push(3);
+        list.push(2);
+        list.push(1); // 1, 2, 3
+
+        let tail = list.split_off(1);
+
+        let mut list_iter = list.into_iter();
+        assert_eq!(list_iter.next(), Some(1));
+        assert_eq!(list_iter.next(), None);
+
+        let mut tail_iter = tail.into_iter();
+        assert_eq!(tail_iter.next(), Some(2));
+        assert_eq!(tail_iter.next(), Some(3));
+        assert_eq!(tail_iter.next(), None);
+    }
+
+    #[test]
+    fn split_off_out_of_bounds() {
+        let mut list = List::new();
+        list.push(2);
+        list.push(1); // 1, 2
+
+        let tail = list.split_off(5);
+        assert!(tail.into_iter().next().is_none());
+
+        let mut list_iter = list.into_iter();
+        assert_eq!(list_iter.next(), Some(1));
+        assert_eq!(list_iter.next(), Some(2));
+        assert_eq!(list_iter.next(), None);
+    }
+
+    #[test]
+    fn append() {
+        let mut list = List::new();
+        list.push(2);
+        list.push(1); // 1, 2
+
+        let mut other = List::new();
+        other.push(4);
+        other.push(3); // 3, 4
+
+        list.append(&mut other);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), Some(4));
+        assert_eq!(iter.next(), None);
+
+        assert!(other.into_iter().next().is_none());
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut list = List::new();
+        list.push(3);
+        list.push(1); // 1, 3
+
+        list.insert(1, 2); // 1, 2, 3
+        list.insert(0, 0); // 0, 1, 2, 3
+        list.insert(100, 4); // out of bounds, no-op
+
+        assert_eq!(list.remove(1), Some(1)); // 0, 2, 3
+        assert_eq!(list.remove(100), None);
+
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), None);
+    }
 }